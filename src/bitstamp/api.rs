@@ -37,6 +37,28 @@ header! {
     (ContentHeader, "Content-Type") => [String]
 }
 
+/// Bitstamp reports request-level failures inside an otherwise well-formed JSON body rather
+/// than via the HTTP status line, either as `{"error": "..."}` or `{"status": "error",
+/// "reason": ...}`. Detect either shape and surface the exchange's own text as
+/// `Error::ExchangeError` instead of letting callers mistake it for a successful response.
+fn check_exchange_error(map: Map<String, Value>) -> Result<Map<String, Value>, Error> {
+    if let Some(error) = map.get("error") {
+        return Err(Error::ExchangeError(value_to_text(error)));
+    }
+    if map.get("status").and_then(|status| status.as_str()) == Some("error") {
+        let reason = map.get("reason").map(value_to_text).unwrap_or_default();
+        return Err(Error::ExchangeError(reason));
+    }
+    Ok(map)
+}
+
+/// Extracts a `Value`'s text: a JSON string is returned as-is, anything else falls back to its
+/// serialized form. Using `to_string()` unconditionally would re-serialize a string value,
+/// wrapping it in escaped quotes instead of surfacing the plain error text.
+fn value_to_text(value: &Value) -> String {
+    value.as_str().map(|s| s.to_string()).unwrap_or_else(|| value.to_string())
+}
+
 #[derive(Debug)]
 pub struct BitstampApi {
     last_request: i64, // unix timestamp in ms, to avoid ban
@@ -49,7 +71,7 @@ pub struct BitstampApi {
 
 impl BitstampApi {
     /// Create a new BitstampApi by providing an API key & API secret
-    pub fn new(params: &HashMap<&str, &str>) -> BitstampApi {
+    pub fn new(params: &HashMap<&str, &str>) -> Result<BitstampApi, Error> {
         let mut params = params.clone();
         helpers::strip_empties(&mut params);
 
@@ -59,16 +81,16 @@ impl BitstampApi {
         let api_secret = params.get("api_secret").unwrap_or(&empty_str);
         let customer_id = params.get("customer_id").unwrap_or(&empty_str);
 
-        let ssl = NativeTlsClient::new().unwrap();
+        let ssl = NativeTlsClient::new().map_err(|err| Error::Http(err.to_string()))?;
         let connector = HttpsConnector::new(ssl);
 
-        BitstampApi {
+        Ok(BitstampApi {
             last_request: 0,
             api_key: api_key.to_string(),
             api_secret: api_secret.to_string(),
             customer_id: customer_id.to_string(),
             http_client: Client::with_connector(connector),
-        }
+        })
     }
 
     /// Create a new BitstampApi from a json configuration file. This file must follow this
@@ -91,16 +113,24 @@ impl BitstampApi {
     /// ```
     /// For this example, you could use load your Bitstamp account with
     /// `new_from_file("account_bitstamp", Path::new("/keys.json"))`
-    pub fn new_from_file(config_name: &str, path: PathBuf) -> BitstampApi {
-        let mut f = File::open(&path).unwrap();
+    pub fn new_from_file(config_name: &str, path: PathBuf) -> Result<BitstampApi, Error> {
+        let mut f = File::open(&path).map_err(|err| Error::Config(err.to_string()))?;
         let mut buffer = String::new();
-        f.read_to_string(&mut buffer).unwrap();
-
-        let data: Value = serde_json::from_str(&buffer).unwrap();
-        let json_obj = data.as_object().unwrap().get(config_name).unwrap();
-        let api_key = json_obj.get("api_key").unwrap().as_str().unwrap();
-        let api_secret = json_obj.get("api_secret").unwrap().as_str().unwrap();
-        let customer_id = json_obj.get("customer_id").unwrap().as_str().unwrap();
+        f.read_to_string(&mut buffer).map_err(|err| Error::Config(err.to_string()))?;
+
+        let data: Value = serde_json::from_str(&buffer).map_err(|err| Error::Config(err.to_string()))?;
+        let json_obj = data.as_object()
+            .and_then(|obj| obj.get(config_name))
+            .ok_or_else(|| Error::Config(format!("no account named \"{}\" in config file", config_name)))?;
+        let api_key = json_obj.get("api_key")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::Config("missing \"api_key\" in config file".to_string()))?;
+        let api_secret = json_obj.get("api_secret")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::Config("missing \"api_secret\" in config file".to_string()))?;
+        let customer_id = json_obj.get("customer_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| Error::Config("missing \"customer_id\" in config file".to_string()))?;
 
         let mut params = HashMap::new();
         params.insert("api_key", api_key);
@@ -118,11 +148,11 @@ impl BitstampApi {
         let url: String = utils::build_url(method, pair);
 
         utils::block_or_continue(self.last_request);
-        let mut response = self.http_client.get(&url).send().unwrap();
+        let mut response = self.http_client.get(&url).send().map_err(|err| Error::Http(err.to_string()))?;
         self.last_request = helpers::get_unix_timestamp_ms();
         let mut buffer = String::new();
-        response.read_to_string(&mut buffer).unwrap();
-        utils::deserialize_json(buffer)
+        response.read_to_string(&mut buffer).map_err(|err| Error::Http(err.to_string()))?;
+        utils::deserialize_json(buffer).and_then(check_exchange_error)
     }
 
     ///
@@ -162,11 +192,11 @@ impl BitstampApi {
             .header(ContentType::form_url_encoded())
             .body(&post_data)
             .send()
-            .unwrap();
+            .map_err(|err| Error::Http(err.to_string()))?;
 
         let mut buffer = String::new();
-        response.read_to_string(&mut buffer).unwrap();
-        utils::deserialize_json(buffer)
+        response.read_to_string(&mut buffer).map_err(|err| Error::Http(err.to_string()))?;
+        utils::deserialize_json(buffer).and_then(check_exchange_error)
     }
 
     /// Sample output :
@@ -251,23 +281,64 @@ impl BitstampApi {
         self.private_query(&params)
     }
 
+    /// Returns Bitstamp's documented minimum order quantity for the specified Pair; a
+    /// conservative default is used for pairs without a documented minimum. The quantity
+    /// traded is denominated in the *base* currency (e.g. "ethbtc" trades ETH, quoted in BTC),
+    /// so match on that leading prefix rather than `contains` — otherwise a pair like "ethbtc"
+    /// would match the "btc" branch just because the quote currency code appears in it.
+    pub(crate) fn min_order_volume(&self, pair: Pair) -> Volume {
+        match utils::get_pair_string(&pair) {
+            Some(pair_name) if pair_name.starts_with("btc") => Volume::new(1, 3), // 0.001 BTC
+            Some(pair_name) if pair_name.starts_with("eth") => Volume::new(1, 2), // 0.01 ETH
+            _ => Volume::new(1, 2), // 0.01, conservative default
+        }
+    }
+
+    /// Builds a simulated response for an order whose parameters were built but not sent,
+    /// so dry-run callers get a shape-compatible result without a live order ever existing. No
+    /// "id" key is inserted since no order identifier was actually assigned.
+    fn simulated_order_result(&self, params: &HashMap<&str, &str>) -> Map<String, Value> {
+        let mut result = Map::new();
+        result.insert("datetime".to_string(),
+                      Value::String(helpers::get_unix_timestamp_ms().to_string()));
+        for (key, value) in params.iter() {
+            if *key != "method" {
+                result.insert(key.to_string(), Value::String(value.to_string()));
+            }
+        }
+        result
+    }
+
     /// Add a buy limit order to the exchange
     /// limit_price	: If the order gets executed, a new sell order will be placed,
     /// with "limit_price" as its price.
     /// daily_order (Optional) : Opens buy limit order which will be canceled
     /// at 0:00 UTC unless it already has been executed. Possible value: True
+    /// dry_run : If true, the order's parameters are built but the request is neither signed
+    /// nor submitted, and a simulated result is returned instead.
     pub fn buy_limit(&mut self,
                      pair: Pair,
                      amount: Volume,
                      price: Price,
                      price_limit: Option<Price>,
-                     daily_order: Option<bool>)
+                     daily_order: Option<bool>,
+                     dry_run: bool)
                      -> Result<Map<String, Value>, error::Error> {
         let pair_name = match utils::get_pair_string(&pair) {
             Some(name) => name,
             None => return Err(Error::PairUnsupported),
         };
 
+        let min_volume = self.min_order_volume(pair);
+        if amount < min_volume {
+            return Err(Error::OrderTooSmall {
+                min: min_volume,
+                requested: amount,
+            });
+        }
+
+        // `Volume`/`Price` are `Decimal`, so this round-trips the exact amount the caller
+        // passed in instead of the lossy re-formatting an `f64` would produce.
         let amount_string = amount.to_string();
         let price_string = price.to_string();
         let price_limit_string = match price_limit {
@@ -290,6 +361,10 @@ impl BitstampApi {
             params.insert("daily_order", daily_order_str);
         }
 
+        if dry_run {
+            return Ok(self.simulated_order_result(&params));
+        }
+
         self.private_query(&params)
     }
 
@@ -298,18 +373,31 @@ impl BitstampApi {
     /// with "limit_price" as its price.
     /// daily_order (Optional) : Opens sell limit order which will be canceled
     /// at 0:00 UTC unless it already has been executed. Possible value: True
+    /// dry_run : If true, the order's parameters are built but the request is neither signed
+    /// nor submitted, and a simulated result is returned instead.
     pub fn sell_limit(&mut self,
                       pair: Pair,
                       amount: Volume,
                       price: Price,
                       price_limit: Option<Price>,
-                      daily_order: Option<bool>)
+                      daily_order: Option<bool>,
+                      dry_run: bool)
                       -> Result<Map<String, Value>, error::Error> {
         let pair_name = match utils::get_pair_string(&pair) {
             Some(name) => name,
             None => return Err(Error::PairUnsupported),
         };
 
+        let min_volume = self.min_order_volume(pair);
+        if amount < min_volume {
+            return Err(Error::OrderTooSmall {
+                min: min_volume,
+                requested: amount,
+            });
+        }
+
+        // `Volume`/`Price` are `Decimal`, so this round-trips the exact amount the caller
+        // passed in instead of the lossy re-formatting an `f64` would produce.
         let amount_string = amount.to_string();
         let price_string = price.to_string();
         let price_limit_string = match price_limit {
@@ -332,6 +420,10 @@ impl BitstampApi {
             params.insert("daily_order", daily_order_str);
         }
 
+        if dry_run {
+            return Ok(self.simulated_order_result(&params));
+        }
+
         self.private_query(&params)
     }
 
@@ -339,15 +431,28 @@ impl BitstampApi {
     /// By placing a market order you acknowledge that the execution of your order depends
     /// on the market conditions and that these conditions may be subject to sudden changes
     /// that cannot be foreseen.
+    /// dry_run : If true, the order's parameters are built but the request is neither signed
+    /// nor submitted, and a simulated result is returned instead.
     pub fn buy_market(&mut self,
                       pair: Pair,
-                      amount: Volume)
+                      amount: Volume,
+                      dry_run: bool)
                       -> Result<Map<String, Value>, error::Error> {
         let pair_name = match utils::get_pair_string(&pair) {
             Some(name) => name,
             None => return Err(Error::PairUnsupported),
         };
 
+        let min_volume = self.min_order_volume(pair);
+        if amount < min_volume {
+            return Err(Error::OrderTooSmall {
+                min: min_volume,
+                requested: amount,
+            });
+        }
+
+        // `Volume`/`Price` are `Decimal`, so this round-trips the exact amount the caller
+        // passed in instead of the lossy re-formatting an `f64` would produce.
         let amount_string = amount.to_string();
 
         let mut params = HashMap::new();
@@ -356,6 +461,10 @@ impl BitstampApi {
 
         params.insert("amount", &amount_string);
 
+        if dry_run {
+            return Ok(self.simulated_order_result(&params));
+        }
+
         self.private_query(&params)
     }
 
@@ -363,15 +472,28 @@ impl BitstampApi {
     /// By placing a market order you acknowledge that the execution of your order depends
     /// on the market conditions and that these conditions may be subject to sudden changes
     /// that cannot be foreseen.
+    /// dry_run : If true, the order's parameters are built but the request is neither signed
+    /// nor submitted, and a simulated result is returned instead.
     pub fn sell_market(&mut self,
                        pair: Pair,
-                       amount: Volume)
+                       amount: Volume,
+                       dry_run: bool)
                        -> Result<Map<String, Value>, error::Error> {
         let pair_name = match utils::get_pair_string(&pair) {
             Some(name) => name,
             None => return Err(Error::PairUnsupported),
         };
 
+        let min_volume = self.min_order_volume(pair);
+        if amount < min_volume {
+            return Err(Error::OrderTooSmall {
+                min: min_volume,
+                requested: amount,
+            });
+        }
+
+        // `Volume`/`Price` are `Decimal`, so this round-trips the exact amount the caller
+        // passed in instead of the lossy re-formatting an `f64` would produce.
         let amount_string = amount.to_string();
 
         let mut params = HashMap::new();
@@ -380,6 +502,10 @@ impl BitstampApi {
 
         params.insert("amount", &amount_string);
 
+        if dry_run {
+            return Ok(self.simulated_order_result(&params));
+        }
+
         self.private_query(&params)
     }
 }
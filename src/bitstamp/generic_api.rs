@@ -0,0 +1,101 @@
+//! Implements `ExchangeApi` for `BitstampApi`, the safe, Result-returning interface on top of
+//! Bitstamp's lower-level REST helpers (`return_ticker`, `buy_limit`, ...). Mirrors
+//! `kraken::generic_api`.
+
+use rust_decimal::Decimal;
+use serde_json::Value;
+
+use bitstamp::api::BitstampApi;
+use error::Error;
+use exchange::ExchangeApi;
+use helpers;
+use pair::Pair;
+use types::*;
+
+/// Reads a Bitstamp string-encoded decimal out of a JSON value, turning a missing field or an
+/// amount that doesn't parse into a recoverable `Error::Parse` instead of a panic.
+fn parse_decimal(value: &Value) -> Result<Decimal, Error> {
+    value.as_str()
+        .ok_or_else(|| Error::Parse(format!("expected a string value, got {}", value)))?
+        .parse::<Decimal>()
+        .map_err(|err| Error::Parse(err.to_string()))
+}
+
+impl ExchangeApi for BitstampApi {
+    fn ticker(&mut self, pair: Pair) -> Result<Ticker, Error> {
+        let result = self.return_ticker(pair)?;
+
+        Ok(Ticker {
+            timestamp: helpers::get_unix_timestamp_ms(),
+            pair: pair,
+            last_trade_price: parse_decimal(&result["last"])?,
+            lowest_ask: parse_decimal(&result["ask"])?,
+            highest_bid: parse_decimal(&result["bid"])?,
+            volume: Some(parse_decimal(&result["volume"])?),
+        })
+    }
+
+    fn orderbook(&mut self, pair: Pair) -> Result<Orderbook, Error> {
+        let result = self.return_order_book(pair)?;
+
+        let ask_array = result["asks"].as_array()
+            .ok_or_else(|| Error::Parse("expected \"asks\" to be an array".to_string()))?;
+        let bid_array = result["bids"].as_array()
+            .ok_or_else(|| Error::Parse("expected \"bids\" to be an array".to_string()))?;
+
+        let mut asks = Vec::new();
+        let mut bids = Vec::new();
+
+        for ask in ask_array {
+            asks.push((parse_decimal(&ask[0])?, parse_decimal(&ask[1])?));
+        }
+
+        for bid in bid_array {
+            bids.push((parse_decimal(&bid[0])?, parse_decimal(&bid[1])?));
+        }
+
+        Ok(Orderbook {
+            timestamp: helpers::get_unix_timestamp_ms(),
+            pair: pair,
+            asks: asks,
+            bids: bids,
+        })
+    }
+
+    fn min_order_volume(&self, pair: Pair) -> Volume {
+        // Delegates to the inherent helper that `buy_limit`/`sell_limit`/... already use; the
+        // inherent method takes priority in method resolution, so this isn't recursive.
+        self.min_order_volume(pair)
+    }
+
+    fn add_order(&mut self,
+                 order_type: OrderType,
+                 pair: Pair,
+                 quantity: Volume,
+                 price: Option<Price>,
+                 dry_run: bool)
+                 -> Result<OrderInfo, Error> {
+        let result = match order_type {
+            OrderType::BuyLimit => {
+                let price = price.ok_or_else(|| Error::Config("a price is required for a limit order".to_string()))?;
+                self.buy_limit(pair, quantity, price, None, None, dry_run)?
+            }
+            OrderType::SellLimit => {
+                let price = price.ok_or_else(|| Error::Config("a price is required for a limit order".to_string()))?;
+                self.sell_limit(pair, quantity, price, None, None, dry_run)?
+            }
+            OrderType::BuyMarket => self.buy_market(pair, quantity, dry_run)?,
+            OrderType::SellMarket => self.sell_market(pair, quantity, dry_run)?,
+        };
+
+        let identifier = match result.get("id") {
+            Some(id) => vec![id.as_str().map(|s| s.to_string()).unwrap_or_else(|| id.to_string())],
+            None => Vec::new(),
+        };
+
+        Ok(OrderInfo {
+            timestamp: helpers::get_unix_timestamp_ms(),
+            identifier: identifier,
+        })
+    }
+}
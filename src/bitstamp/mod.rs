@@ -0,0 +1,7 @@
+//! Bitstamp exchange adapter.
+
+pub mod api;
+pub mod generic_api;
+pub mod utils;
+
+pub use self::api::BitstampApi;
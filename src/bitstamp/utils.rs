@@ -0,0 +1,80 @@
+//! Small helpers for translating between coinnect's generic types and Bitstamp's API, and for
+//! building/signing requests.
+
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use serde_json;
+use serde_json::Value;
+use serde_json::value::Map;
+use sha2::Sha256;
+
+use error::Error;
+use helpers;
+use pair::Pair;
+
+lazy_static! {
+    static ref PAIRS_STRING: HashMap<Pair, &'static str> = {
+        let mut map = HashMap::new();
+        map.insert(Pair::BTC_EUR, "btceur");
+        map.insert(Pair::BTC_USD, "btcusd");
+        map.insert(Pair::ETH_BTC, "ethbtc");
+        map.insert(Pair::ETH_EUR, "etheur");
+        map.insert(Pair::ETH_USD, "ethusd");
+        map.insert(Pair::LTC_BTC, "ltcbtc");
+        map.insert(Pair::LTC_EUR, "ltceur");
+        map.insert(Pair::LTC_USD, "ltcusd");
+        map
+    };
+}
+
+/// Translates a generic Pair into the pair code Bitstamp's API expects.
+pub fn get_pair_string(pair: &Pair) -> Option<&'static &'static str> {
+    PAIRS_STRING.get(pair)
+}
+
+/// Bitstamp rate-limits to roughly one request per second; block the calling thread until that
+/// much time has passed since `last_request`.
+pub fn block_or_continue(last_request: i64) {
+    let min_interval_ms = 1000;
+    let elapsed = helpers::get_unix_timestamp_ms() - last_request;
+    if elapsed < min_interval_ms {
+        thread::sleep(Duration::from_millis((min_interval_ms - elapsed) as u64));
+    }
+}
+
+/// Builds the full URL for a Bitstamp REST endpoint.
+pub fn build_url(method: &str, pair: &str) -> String {
+    format!("https://www.bitstamp.net/api/v2/{}/{}/", method, pair)
+}
+
+/// Parses a raw HTTP response body into a JSON object.
+pub fn deserialize_json(buffer: String) -> Result<Map<String, Value>, Error> {
+    let data: Value = serde_json::from_str(&buffer).map_err(|err| Error::Parse(err.to_string()))?;
+    data.as_object()
+        .cloned()
+        .ok_or_else(|| Error::Parse("expected a JSON object".to_string()))
+}
+
+/// Generates a nonce for signed requests from the current unix timestamp in milliseconds; two
+/// calls within the same millisecond return the same nonce.
+pub fn generate_nonce(_: Option<i64>) -> String {
+    helpers::get_unix_timestamp_ms().to_string()
+}
+
+/// Builds Bitstamp's request signature: `HMAC-SHA256(api_secret, nonce + customer_id +
+/// api_key)`, uppercase hex-encoded.
+pub fn build_signature(nonce: String, customer_id: String, api_key: String, api_secret: String) -> String {
+    let message = format!("{}{}{}", nonce, customer_id, api_key);
+
+    let mut mac = Hmac::<Sha256>::new_varkey(api_secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.input(message.as_bytes());
+
+    mac.result()
+        .code()
+        .iter()
+        .map(|byte| format!("{:02X}", byte))
+        .collect()
+}
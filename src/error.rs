@@ -0,0 +1,56 @@
+//! Error type shared by every exchange adapter.
+
+use std::error;
+use std::fmt;
+
+use types::Volume;
+
+/// A unified error type returned by every Coinnect API call.
+#[derive(Debug)]
+pub enum Error {
+    /// The requested Pair is not supported/tradable on this exchange.
+    PairUnsupported,
+    /// A network-level failure: DNS, TLS, connection refused, or an unreadable HTTP response.
+    Http(String),
+    /// The exchange's response body was not the JSON shape this call expected.
+    Parse(String),
+    /// Something is wrong with local configuration: an unreadable/malformed keys file, or a
+    /// required field is missing from it. Also returned when an order is requested without a
+    /// required argument, such as a limit order with no price.
+    Config(String),
+    /// The exchange rejected the request and returned its own error text.
+    ExchangeError(String),
+    /// The requested quantity is below the exchange's documented minimum order size.
+    OrderTooSmall { min: Volume, requested: Volume },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::PairUnsupported => write!(f, "this pair is not supported by the exchange"),
+            Error::Http(ref msg) => write!(f, "HTTP request failed: {}", msg),
+            Error::Parse(ref msg) => write!(f, "failed to parse exchange response: {}", msg),
+            Error::Config(ref msg) => write!(f, "invalid configuration: {}", msg),
+            Error::ExchangeError(ref msg) => write!(f, "exchange returned an error: {}", msg),
+            Error::OrderTooSmall { min, requested } => {
+                write!(f,
+                       "requested order volume {} is below the exchange's minimum of {}",
+                       requested,
+                       min)
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::PairUnsupported => "pair unsupported",
+            Error::Http(ref msg) => msg,
+            Error::Parse(ref msg) => msg,
+            Error::Config(ref msg) => msg,
+            Error::ExchangeError(ref msg) => msg,
+            Error::OrderTooSmall { .. } => "order volume too small",
+        }
+    }
+}
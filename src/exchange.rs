@@ -1,6 +1,7 @@
 //! This module contains Exchange enum.
 
 use std::fmt::Debug;
+use std::sync::mpsc::Receiver;
 
 use error::Error;
 use pair::Pair;
@@ -21,6 +22,12 @@ pub trait ExchangeApi: Debug {
     /// Return an Orderbook for the specified Pair.
     fn orderbook(&mut self, pair: Pair) -> Result<Orderbook, Error>;
 
+    /// Return the minimum order quantity the exchange accepts for the specified Pair. Orders
+    /// below this amount are rejected by the exchange itself; `add_order` checks against it up
+    /// front so callers get a deterministic local `Error::OrderTooSmall` instead of paying for
+    /// a round-trip that was always going to fail.
+    fn min_order_volume(&self, pair: Pair) -> Volume;
+
     /// Place an order directly to the exchange.
     /// Quantity is in quote currency. So if you want to buy 1 Bitcoin for X€ (pair BTC_EUR),
     /// base currency (right member in the pair) is BTC and quote/counter currency is BTC (left
@@ -29,10 +36,31 @@ pub trait ExchangeApi: Debug {
     ///
     /// A good practice is to store the return type (OrderInfo) somewhere since it can later be used
     /// to modify or cancel the order.
+    ///
+    /// Set `dry_run` to validate the order without actually submitting it to the exchange.
+    /// What is checked depends on the exchange: Kraken validates the order server-side, while
+    /// Bitstamp only checks pair support and the minimum order volume locally and does not
+    /// contact the exchange, so balance sufficiency is not checked there. The returned
+    /// OrderInfo is simulated: its `identifier` is empty since no real order was placed.
     fn add_order(&mut self,
                  order_type: OrderType,
                  pair: Pair,
                  quantity: Volume,
-                 price: Option<Price>)
+                 price: Option<Price>,
+                 dry_run: bool)
                  -> Result<OrderInfo, Error>;
 }
+
+/// Gives access to an exchange's live push feed, as an alternative to polling `ExchangeApi`
+/// over HTTP. Each subscription spawns the connection (or reuses an existing one) and hands
+/// back the receiving end of a channel that the feed is pushed down as updates arrive, so
+/// callers don't have to block waiting on the socket themselves.
+pub trait StreamingApi: Debug {
+    /// Subscribe to the live ticker feed for the specified Pair.
+    /// The returned Receiver yields a new Ticker every time the exchange pushes an update.
+    fn subscribe_ticker(&mut self, pair: Pair) -> Result<Receiver<Ticker>, Error>;
+
+    /// Subscribe to the live orderbook feed for the specified Pair.
+    /// The returned Receiver yields a new Orderbook every time the exchange pushes an update.
+    fn subscribe_orderbook(&mut self, pair: Pair) -> Result<Receiver<Orderbook>, Error>;
+}
@@ -0,0 +1,34 @@
+//! Small utilities shared across exchange adapters.
+
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the current unix timestamp, in milliseconds.
+pub fn get_unix_timestamp_ms() -> i64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system clock is before the unix epoch");
+    now.as_secs() as i64 * 1000 + (now.subsec_nanos() / 1_000_000) as i64
+}
+
+/// Removes every entry whose value is the empty string, since exchanges generally want
+/// optional parameters omitted rather than sent as `""`.
+pub fn strip_empties(params: &mut HashMap<&str, &str>) {
+    params.retain(|_, value| !value.is_empty());
+}
+
+/// URL-encodes a flat `key=value&...` query string from a parameter map.
+pub fn url_encode_hashmap(params: &HashMap<&str, &str>) -> String {
+    let encode = |s: &str| -> String {
+        s.bytes()
+            .map(|b| match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+                _ => format!("%{:02X}", b),
+            })
+            .collect()
+    };
+    params.iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<String>>()
+        .join("&")
+}
@@ -0,0 +1,163 @@
+//! Use this module to interact with Kraken through its native REST API.
+//! Please see examples for more informations.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use hmac::{Hmac, Mac};
+use hyper::Client;
+use hyper::net::HttpsConnector;
+use hyper_native_tls::NativeTlsClient;
+use serde_json::Value;
+use sha2::{Digest, Sha256, Sha512};
+
+use error::Error;
+use helpers;
+
+header! {
+    #[doc(hidden)]
+    (KeyHeader, "API-Key") => [String]
+}
+
+header! {
+    #[doc(hidden)]
+    (SignHeader, "API-Sign") => [String]
+}
+
+#[derive(Debug)]
+pub struct KrakenApi {
+    last_request: i64, // unix timestamp in ms, to avoid ban
+    api_key: String,
+    api_secret: String,
+    http_client: Client,
+}
+
+impl KrakenApi {
+    /// Create a new KrakenApi by providing an API key & API secret.
+    pub fn new(params: &HashMap<&str, &str>) -> Result<KrakenApi, Error> {
+        let mut params = params.clone();
+        helpers::strip_empties(&mut params);
+
+        let empty_str: &str = "";
+        let api_key = params.get("api_key").unwrap_or(&empty_str);
+        let api_secret = params.get("api_secret").unwrap_or(&empty_str);
+
+        let ssl = NativeTlsClient::new().map_err(|err| Error::Http(err.to_string()))?;
+        let connector = HttpsConnector::new(ssl);
+
+        Ok(KrakenApi {
+            last_request: 0,
+            api_key: api_key.to_string(),
+            api_secret: api_secret.to_string(),
+            http_client: Client::with_connector(connector),
+        })
+    }
+
+    fn build_url(method: &str) -> String {
+        format!("https://api.kraken.com/0/{}", method)
+    }
+
+    fn public_query(&mut self, method: &str, params: &HashMap<&str, &str>) -> Result<Value, Error> {
+        let url = KrakenApi::build_url(&format!("public/{}", method));
+        let post_data = helpers::url_encode_hashmap(params);
+
+        let mut response = self.http_client
+            .post(&url)
+            .body(&post_data)
+            .send()
+            .map_err(|err| Error::Http(err.to_string()))?;
+        self.last_request = helpers::get_unix_timestamp_ms();
+
+        let mut buffer = String::new();
+        response.read_to_string(&mut buffer).map_err(|err| Error::Http(err.to_string()))?;
+
+        ::serde_json::from_str(&buffer).map_err(|err| Error::Parse(err.to_string()))
+    }
+
+    /// Builds Kraken's HMAC-SHA512 request signature: `HMAC-SHA512(base64_decode(secret),
+    /// path + SHA256(nonce + post_data))`, base64-encoded.
+    fn sign(&self, path: &str, nonce: &str, post_data: &str) -> Result<String, Error> {
+        let mut sha256 = Sha256::new();
+        sha256.input(nonce.as_bytes());
+        sha256.input(post_data.as_bytes());
+        let hashed_post = sha256.result();
+
+        let secret = ::base64::decode(&self.api_secret).map_err(|err| Error::Config(err.to_string()))?;
+        let mut mac = Hmac::<Sha512>::new_varkey(&secret).map_err(|err| Error::Config(err.to_string()))?;
+        mac.input(path.as_bytes());
+        mac.input(&hashed_post);
+
+        Ok(::base64::encode(&mac.result().code()))
+    }
+
+    fn private_query(&mut self, method: &str, params: &HashMap<&str, &str>) -> Result<Value, Error> {
+        let path = format!("/0/private/{}", method);
+        let url = KrakenApi::build_url(&format!("private/{}", method));
+
+        let nonce = helpers::get_unix_timestamp_ms().to_string();
+        let mut params = params.clone();
+        params.insert("nonce", &nonce);
+
+        let post_data = helpers::url_encode_hashmap(&params);
+        let signature = self.sign(&path, &nonce, &post_data)?;
+
+        let mut response = self.http_client
+            .post(&url)
+            .header(KeyHeader(self.api_key.clone()))
+            .header(SignHeader(signature))
+            .body(&post_data)
+            .send()
+            .map_err(|err| Error::Http(err.to_string()))?;
+        self.last_request = helpers::get_unix_timestamp_ms();
+
+        let mut buffer = String::new();
+        response.read_to_string(&mut buffer).map_err(|err| Error::Http(err.to_string()))?;
+
+        ::serde_json::from_str(&buffer).map_err(|err| Error::Parse(err.to_string()))
+    }
+
+    pub fn get_ticker_information(&mut self, pair: &str) -> Result<Value, Error> {
+        let mut params = HashMap::new();
+        params.insert("pair", pair);
+        self.public_query("Ticker", &params)
+    }
+
+    pub fn get_order_book(&mut self, pair: &str, count: &str) -> Result<Value, Error> {
+        let mut params = HashMap::new();
+        params.insert("pair", pair);
+        params.insert("count", count);
+        self.public_query("Depth", &params)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_standard_order(&mut self,
+                              pair: &str,
+                              direction: &str,
+                              order_type: &str,
+                              price: &str,
+                              price2: &str,
+                              volume: &str,
+                              leverage: &str,
+                              oflags: &str,
+                              starttm: &str,
+                              expiretm: &str,
+                              userref: &str,
+                              validate: &str)
+                              -> Result<Value, Error> {
+        let mut params = HashMap::new();
+        params.insert("pair", pair);
+        params.insert("type", direction);
+        params.insert("ordertype", order_type);
+        params.insert("price", price);
+        params.insert("price2", price2);
+        params.insert("volume", volume);
+        params.insert("leverage", leverage);
+        params.insert("oflags", oflags);
+        params.insert("starttm", starttm);
+        params.insert("expiretm", expiretm);
+        params.insert("userref", userref);
+        params.insert("validate", validate);
+        helpers::strip_empties(&mut params);
+        self.private_query("AddOrder", &params)
+    }
+}
@@ -2,6 +2,9 @@
 //! This a more convenient and safe way to deal with the exchange since methods return a Result<>
 //! but this generic API does not provide all the functionnality that Kraken offers.
 
+use rust_decimal::Decimal;
+use serde_json::Value;
+
 use exchange::ExchangeApi;
 use kraken::api::KrakenApi;
 
@@ -11,6 +14,15 @@ use types::*;
 use kraken::utils;
 use helpers;
 
+/// Reads a Kraken-style string-encoded decimal out of a JSON value, turning a missing field or
+/// an amount that doesn't parse into a recoverable `Error::Parse` instead of a panic.
+fn parse_decimal(value: &Value) -> Result<Decimal, Error> {
+    value.as_str()
+        .ok_or_else(|| Error::Parse(format!("expected a string value, got {}", value)))?
+        .parse::<Decimal>()
+        .map_err(|err| Error::Parse(err.to_string()))
+}
+
 impl ExchangeApi for KrakenApi {
     fn ticker(&mut self, pair: Pair) -> Result<Ticker, Error> {
         let pair_name = match utils::get_pair_string(&pair) {
@@ -22,10 +34,10 @@ impl ExchangeApi for KrakenApi {
 
         let result = utils::parse_result(raw_response)?;
 
-        let price = result[*pair_name]["c"][0].as_str().unwrap().parse::<f64>().unwrap();
-        let ask = result[*pair_name]["a"][0].as_str().unwrap().parse::<f64>().unwrap();
-        let bid = result[*pair_name]["b"][0].as_str().unwrap().parse::<f64>().unwrap();
-        let vol = result[*pair_name]["v"][1].as_str().unwrap().parse::<f64>().unwrap();
+        let price = parse_decimal(&result[*pair_name]["c"][0])?;
+        let ask = parse_decimal(&result[*pair_name]["a"][0])?;
+        let bid = parse_decimal(&result[*pair_name]["b"][0])?;
+        let vol = parse_decimal(&result[*pair_name]["v"][1])?;
 
         Ok(Ticker {
             timestamp: helpers::get_unix_timestamp_ms(),
@@ -51,18 +63,20 @@ impl ExchangeApi for KrakenApi {
         let mut ask_offers = Vec::new();
         let mut bid_offers = Vec::new();
 
-        let ask_array = result[*pair_name]["asks"].as_array().unwrap();
-        let bid_array = result[*pair_name]["bids"].as_array().unwrap();
+        let ask_array = result[*pair_name]["asks"].as_array()
+            .ok_or_else(|| Error::Parse("expected \"asks\" to be an array".to_string()))?;
+        let bid_array = result[*pair_name]["bids"].as_array()
+            .ok_or_else(|| Error::Parse("expected \"bids\" to be an array".to_string()))?;
 
         for ask in ask_array {
-            let price = ask[0].as_str().unwrap().parse::<f64>().unwrap();
-            let volume = ask[1].as_str().unwrap().parse::<f64>().unwrap();
+            let price = parse_decimal(&ask[0])?;
+            let volume = parse_decimal(&ask[1])?;
             ask_offers.push((price, volume));
         }
 
         for bid in bid_array {
-            let price = bid[0].as_str().unwrap().parse::<f64>().unwrap();
-            let volume = bid[1].as_str().unwrap().parse::<f64>().unwrap();
+            let price = parse_decimal(&bid[0])?;
+            let volume = parse_decimal(&bid[1])?;
             bid_offers.push((price, volume));
         }
 
@@ -74,17 +88,43 @@ impl ExchangeApi for KrakenApi {
         })
     }
 
+    fn min_order_volume(&self, pair: Pair) -> Volume {
+        // Kraken's documented minimum order sizes; conservative default for anything else.
+        // The quantity traded is denominated in the *base* currency, i.e. the leading asset
+        // code of the pair (e.g. "XETHXXBT" trades ETH, quoted in BTC) — match on that prefix
+        // rather than `contains`, since a quote-currency code can itself be a substring match
+        // for another asset (e.g. "XETHXXBT" contains "XBT").
+        match utils::get_pair_string(&pair) {
+            Some(pair_name) if pair_name.starts_with("XXBT") || pair_name.starts_with("XBT") =>
+                Decimal::new(2, 3), // 0.002 BTC
+            Some(pair_name) if pair_name.starts_with("XETH") || pair_name.starts_with("ETH") =>
+                Decimal::new(2, 2), // 0.02 ETH
+            Some(pair_name) if pair_name.starts_with("XLTC") || pair_name.starts_with("LTC") =>
+                Decimal::new(1, 1), // 0.1 LTC
+            _ => Decimal::new(1, 2), // 0.01, conservative default
+        }
+    }
+
     fn add_order(&mut self,
                  order_type: OrderType,
                  pair: Pair,
                  quantity: Volume,
-                 price: Option<Price>)
+                 price: Option<Price>,
+                 dry_run: bool)
                  -> Result<OrderInfo, Error> {
         let pair_name = match utils::get_pair_string(&pair) {
             Some(name) => name,
             None => return Err(Error::PairUnsupported),
         };
 
+        let min_volume = self.min_order_volume(pair);
+        if quantity < min_volume {
+            return Err(Error::OrderTooSmall {
+                min: min_volume,
+                requested: quantity,
+            });
+        }
+
         let direction = match order_type {
             OrderType::BuyLimit => "buy",
             OrderType::BuyMarket => "buy",
@@ -104,6 +144,10 @@ impl ExchangeApi for KrakenApi {
             price_str = price.unwrap().to_string()
         };
 
+        // Kraken validates the order server-side without submitting it when `validate` is set,
+        // which is exactly what a dry run needs.
+        let validate_str = if dry_run { "true" } else { "" };
+
         let raw_response = self.add_standard_order(&pair_name,
                                 direction,
                                 order_type_str,
@@ -115,14 +159,20 @@ impl ExchangeApi for KrakenApi {
                                 "",
                                 "",
                                 "",
-                                "")?;
+                                validate_str)?;
 
         let result = utils::parse_result(raw_response)?;
 
         let mut txids = Vec::new();
 
-        for id in result["txid"].as_array().unwrap() {
-            txids.push(id.as_str().unwrap().to_string());
+        if !dry_run {
+            let txid_array = result["txid"].as_array()
+                .ok_or_else(|| Error::Parse("expected \"txid\" to be an array".to_string()))?;
+            for id in txid_array {
+                let txid = id.as_str()
+                    .ok_or_else(|| Error::Parse("expected txid entries to be strings".to_string()))?;
+                txids.push(txid.to_string());
+            }
         }
 
         Ok(OrderInfo {
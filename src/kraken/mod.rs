@@ -0,0 +1,8 @@
+//! Kraken exchange adapter.
+
+pub mod api;
+pub mod generic_api;
+pub mod streaming;
+pub mod utils;
+
+pub use self::api::KrakenApi;
@@ -0,0 +1,211 @@
+//! WebSocket streaming support for Kraken, implementing `StreamingApi`.
+//!
+//! Kraken's public feed (`wss://ws.kraken.com`) is subscribed to by sending a frame naming the
+//! `ticker`/`book` channel and the translated pair string; every message afterwards is a JSON
+//! array whose second element is either the channel's data object or a heartbeat/system-status
+//! value. `KrakenWsFrame` is modelled as an untagged enum so those heartbeat and status frames
+//! simply fall through to the `Other` variant instead of failing to deserialize.
+
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use serde_json;
+use serde_json::Value;
+use serde_json::value::Map;
+use ws;
+
+use error::Error;
+use exchange::StreamingApi;
+use helpers;
+use kraken::api::KrakenApi;
+use kraken::utils;
+use pair::Pair;
+use types::*;
+
+const KRAKEN_WS_URL: &str = "wss://ws.kraken.com";
+/// Delay, in seconds, before a reconnect attempt, so a persistently unreachable endpoint is
+/// retried instead of hammered.
+const RECONNECT_DELAY_SECS: u64 = 5;
+
+/// One line of Kraken's public WebSocket feed.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum KrakenWsFrame {
+    /// `[channelID, data, channelName, pair]` — ticker updates, and book updates/snapshots
+    /// where only one side (or neither, for a snapshot's `as`/`bs`) changed.
+    Update(u64, Value, String, String),
+    /// `[channelID, asks, bids, channelName, pair]` — a book update where both the ask and bid
+    /// side changed in the same message, so Kraken sends them as two separate data objects.
+    BookUpdate(u64, Value, Value, String, String),
+    /// Heartbeats, subscription acks and system-status frames. Ignored.
+    Other(Value),
+}
+
+/// Combines the two data objects of a `BookUpdate` frame into the single object shape
+/// `parse_book_data` expects, so a same-message ask+bid update is handled exactly like any
+/// other partial update.
+fn merge_book_sides(asks: Value, bids: Value) -> Value {
+    let mut merged = asks.as_object().cloned().unwrap_or_else(Map::new);
+    if let Some(bids_obj) = bids.as_object() {
+        for (key, value) in bids_obj.iter() {
+            merged.insert(key.clone(), value.clone());
+        }
+    }
+    Value::Object(merged)
+}
+
+impl KrakenApi {
+    /// Opens a `wss` connection subscribed to `channel_name` for `pair_name`, reconnecting
+    /// automatically whenever the socket is closed by the server or the network.
+    fn stream<F>(&self, channel_name: &'static str, pair_name: String, on_data: F)
+        where F: Fn(Value) + Send + 'static
+    {
+        thread::spawn(move || {
+            loop {
+                let on_data = &on_data;
+                let pair_name = pair_name.clone();
+                let result = ws::connect(KRAKEN_WS_URL, move |out| {
+                    let subscribe = json!({
+                        "event": "subscribe",
+                        "pair": [pair_name],
+                        "subscription": { "name": channel_name },
+                    });
+
+                    KrakenWsHandler {
+                        out: out,
+                        on_data: on_data,
+                        subscribe_frame: subscribe.to_string(),
+                    }
+                });
+
+                if let Err(err) = result {
+                    warn!("kraken websocket connection failed, reconnecting: {}", err);
+                }
+                // `ws::connect` also returns once the server closes the connection, so falling
+                // through here and looping again is how we reconnect after a clean close too.
+                // Back off before retrying rather than busy-looping against an endpoint that
+                // keeps refusing us.
+                thread::sleep(Duration::from_secs(RECONNECT_DELAY_SECS));
+            }
+        });
+    }
+}
+
+struct KrakenWsHandler<'a, F: 'a> {
+    out: ws::Sender,
+    on_data: &'a F,
+    subscribe_frame: String,
+}
+
+impl<'a, F: Fn(Value) + Send + 'static> ws::Handler for KrakenWsHandler<'a, F> {
+    fn on_open(&mut self, _: ws::Handshake) -> ws::Result<()> {
+        self.out.send(self.subscribe_frame.clone())
+    }
+
+    fn on_message(&mut self, msg: ws::Message) -> ws::Result<()> {
+        let text = msg.into_text()?;
+        match serde_json::from_str::<KrakenWsFrame>(&text) {
+            Ok(KrakenWsFrame::Update(_channel_id, data, _channel_name, _pair)) => {
+                (self.on_data)(data);
+            }
+            Ok(KrakenWsFrame::BookUpdate(_channel_id, asks, bids, _channel_name, _pair)) => {
+                (self.on_data)(merge_book_sides(asks, bids));
+            }
+            Ok(KrakenWsFrame::Other(_)) => {
+                // Heartbeat or subscription-status frame, nothing to forward.
+            }
+            Err(_) => {
+                // Malformed frame: skip it rather than tearing down the connection.
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_ticker_data(pair: Pair, data: &Value) -> Option<Ticker> {
+    let price = data["c"][0].as_str()?.parse().ok()?;
+    let ask = data["a"][0].as_str()?.parse().ok()?;
+    let bid = data["b"][0].as_str()?.parse().ok()?;
+    let vol = data["v"][1].as_str()?.parse().ok()?;
+
+    Some(Ticker {
+        timestamp: helpers::get_unix_timestamp_ms(),
+        pair: pair,
+        last_trade_price: price,
+        lowest_ask: ask,
+        highest_bid: bid,
+        volume: Some(vol),
+    })
+}
+
+/// Parses whichever side(s) are present in a book snapshot or update. Kraken's incremental
+/// updates commonly touch only asks (`a`) or only bids (`b`), so a missing side means "unchanged"
+/// rather than "malformed frame" — the side is simply left empty instead of rejecting the whole
+/// update.
+fn parse_book_data(pair: Pair, data: &Value) -> Option<Orderbook> {
+    let mut asks = Vec::new();
+    let mut bids = Vec::new();
+
+    if let Some(levels) = data["a"].as_array().or_else(|| data["as"].as_array()) {
+        for level in levels {
+            let price = level[0].as_str()?.parse().ok()?;
+            let volume = level[1].as_str()?.parse().ok()?;
+            asks.push((price, volume));
+        }
+    }
+
+    if let Some(levels) = data["b"].as_array().or_else(|| data["bs"].as_array()) {
+        for level in levels {
+            let price = level[0].as_str()?.parse().ok()?;
+            let volume = level[1].as_str()?.parse().ok()?;
+            bids.push((price, volume));
+        }
+    }
+
+    if asks.is_empty() && bids.is_empty() {
+        // Not a book frame at all (e.g. this was actually ticker data).
+        return None;
+    }
+
+    Some(Orderbook {
+        timestamp: helpers::get_unix_timestamp_ms(),
+        pair: pair,
+        asks: asks,
+        bids: bids,
+    })
+}
+
+impl StreamingApi for KrakenApi {
+    fn subscribe_ticker(&mut self, pair: Pair) -> Result<Receiver<Ticker>, Error> {
+        let pair_name = match utils::get_pair_string(&pair) {
+            Some(name) => name,
+            None => return Err(Error::PairUnsupported),
+        };
+
+        let (tx, rx): (Sender<Ticker>, Receiver<Ticker>) = channel();
+        self.stream("ticker", pair_name.to_string(), move |data| {
+            if let Some(ticker) = parse_ticker_data(pair, &data) {
+                let _ = tx.send(ticker);
+            }
+        });
+
+        Ok(rx)
+    }
+
+    fn subscribe_orderbook(&mut self, pair: Pair) -> Result<Receiver<Orderbook>, Error> {
+        let pair_name = match utils::get_pair_string(&pair) {
+            Some(name) => name,
+            None => return Err(Error::PairUnsupported),
+        };
+
+        let (tx, rx): (Sender<Orderbook>, Receiver<Orderbook>) = channel();
+        self.stream("book", pair_name.to_string(), move |data| {
+            if let Some(orderbook) = parse_book_data(pair, &data) {
+                let _ = tx.send(orderbook);
+            }
+        });
+
+        Ok(rx)
+    }
+}
@@ -0,0 +1,47 @@
+//! Small helpers for translating between coinnect's generic types and Kraken's API.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use error::Error;
+use pair::Pair;
+
+lazy_static! {
+    static ref PAIRS_STRING: HashMap<Pair, &'static str> = {
+        let mut map = HashMap::new();
+        map.insert(Pair::BTC_EUR, "XXBTZEUR");
+        map.insert(Pair::BTC_USD, "XXBTZUSD");
+        map.insert(Pair::ETH_BTC, "XETHXXBT");
+        map.insert(Pair::ETH_EUR, "XETHZEUR");
+        map.insert(Pair::ETH_USD, "XETHZUSD");
+        map.insert(Pair::LTC_BTC, "XLTCXXBT");
+        map.insert(Pair::LTC_EUR, "XLTCZEUR");
+        map.insert(Pair::LTC_USD, "XLTCZUSD");
+        map
+    };
+}
+
+/// Translates a generic Pair into the asset-pair code Kraken's API expects.
+pub fn get_pair_string(pair: &Pair) -> Option<&'static &'static str> {
+    PAIRS_STRING.get(pair)
+}
+
+/// Kraken always answers with `{"error": [...], "result": {...}}`; a non-empty `error` array
+/// means the request failed, in which case the exchange's own messages are joined and surfaced
+/// as `Error::ExchangeError` instead of letting callers dig through `result` for data that was
+/// never returned.
+pub fn parse_result(raw_response: Value) -> Result<Value, Error> {
+    let errors = raw_response["error"]
+        .as_array()
+        .ok_or_else(|| Error::Parse("expected \"error\" to be an array".to_string()))?;
+
+    if !errors.is_empty() {
+        let messages: Vec<String> = errors.iter()
+            .map(|err| err.as_str().unwrap_or("").to_string())
+            .collect();
+        return Err(Error::ExchangeError(messages.join(", ")));
+    }
+
+    Ok(raw_response["result"].clone())
+}
@@ -0,0 +1,29 @@
+//! Coinnect is a Rust library aiming to provide a complete and easy-to-use API for
+//! interacting with various crypto currency exchanges.
+
+#[macro_use]
+extern crate hyper;
+extern crate hyper_native_tls;
+extern crate serde;
+#[macro_use]
+extern crate serde_json;
+#[macro_use]
+extern crate serde_derive;
+extern crate rust_decimal;
+#[macro_use]
+extern crate lazy_static;
+#[macro_use]
+extern crate log;
+extern crate ws;
+extern crate hmac;
+extern crate sha2;
+extern crate base64;
+
+pub mod bitstamp;
+pub mod error;
+pub mod exchange;
+pub mod helpers;
+pub mod kraken;
+pub mod pair;
+pub mod rate;
+pub mod types;
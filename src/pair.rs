@@ -0,0 +1,17 @@
+//! Defines the Pair enum, an exchange-agnostic identifier for a currency pair (e.g. `BTC_EUR`
+//! trades Bitcoin, quoted in Euro). Each exchange adapter translates a Pair into whatever string
+//! encoding that exchange expects.
+
+/// An exchange-agnostic currency pair. `Pair::BTC_EUR` reads as "BTC, quoted in EUR".
+#[allow(non_camel_case_types)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pair {
+    BTC_EUR,
+    BTC_USD,
+    ETH_BTC,
+    ETH_EUR,
+    ETH_USD,
+    LTC_BTC,
+    LTC_EUR,
+    LTC_USD,
+}
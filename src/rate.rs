@@ -0,0 +1,113 @@
+//! Pluggable rate providers for market-making style callers: something that can be asked for
+//! "the current ask" without caring whether that comes from a live exchange feed or a static
+//! configured value, plus the spread math a quoting bot needs to bake in a margin.
+
+use rust_decimal::Decimal;
+
+use error::Error;
+use exchange::ExchangeApi;
+use pair::Pair;
+use types::Price;
+
+/// A price quote, before any spread is applied.
+#[derive(Debug, Clone, Copy)]
+pub struct Rate {
+    ask: Price,
+    bid: Price,
+}
+
+impl Rate {
+    pub fn new(ask: Price, bid: Price) -> Rate {
+        Rate {
+            ask: ask,
+            bid: bid,
+        }
+    }
+
+    pub fn ask(&self) -> Price {
+        self.ask
+    }
+
+    pub fn bid(&self) -> Price {
+        self.bid
+    }
+
+    /// The ask price with `spread` (e.g. `0.02` for 2%) added as margin: `ask * (1 + spread)`.
+    pub fn ask_with_spread(&self, spread: Decimal) -> Price {
+        self.ask * (Decimal::new(1, 0) + spread)
+    }
+
+    /// The bid price with `spread` (e.g. `0.02` for 2%) taken off as margin: `bid * (1 - spread)`.
+    pub fn bid_with_spread(&self, spread: Decimal) -> Price {
+        self.bid * (Decimal::new(1, 0) - spread)
+    }
+}
+
+/// Something that can produce a `Rate` on demand, independently of how it is sourced.
+pub trait LatestRate {
+    fn latest_rate(&mut self) -> Result<Rate, Error>;
+}
+
+/// A rate provider that always returns the same, statically configured value. Useful for
+/// testing a quoting bot or for exchanges/pairs where no live feed is available.
+#[derive(Debug)]
+pub struct FixedRate {
+    rate: Rate,
+}
+
+impl FixedRate {
+    pub fn new(rate: Rate) -> FixedRate {
+        FixedRate { rate: rate }
+    }
+}
+
+impl LatestRate for FixedRate {
+    fn latest_rate(&mut self) -> Result<Rate, Error> {
+        Ok(self.rate)
+    }
+}
+
+/// A rate provider that derives its rate from an exchange's own ticker, so a quoting bot always
+/// bases its quotes on the exchange it is actually trading against.
+#[derive(Debug)]
+pub struct TickerRate<'a> {
+    api: &'a mut ExchangeApi,
+    pair: Pair,
+}
+
+impl<'a> TickerRate<'a> {
+    pub fn new(api: &'a mut ExchangeApi, pair: Pair) -> TickerRate<'a> {
+        TickerRate {
+            api: api,
+            pair: pair,
+        }
+    }
+}
+
+impl<'a> LatestRate for TickerRate<'a> {
+    fn latest_rate(&mut self) -> Result<Rate, Error> {
+        let ticker = self.api.ticker(self.pair)?;
+        Ok(Rate::new(ticker.lowest_ask, ticker.highest_bid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ask_with_spread_adds_the_spread_as_margin() {
+        let rate = Rate::new(Decimal::new(10000, 0), Decimal::new(9900, 0));
+
+        // 2% spread on a 10000 ask is 10000 * 1.02 = 10200.
+        assert_eq!(rate.ask_with_spread(Decimal::new(2, 2)), Decimal::new(10200, 0));
+    }
+
+    #[test]
+    fn bid_with_spread_takes_off_the_spread_as_margin() {
+        let rate = Rate::new(Decimal::new(10000, 0), Decimal::new(9900, 0));
+
+        // 2% spread on a 9900 bid is 9900 * 0.98 = 9702.
+        assert_eq!(rate.bid_with_spread(Decimal::new(2, 2)), Decimal::new(9702, 0));
+    }
+}
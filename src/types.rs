@@ -0,0 +1,49 @@
+//! Common types shared by every exchange adapter.
+
+use rust_decimal::Decimal;
+
+use pair::Pair;
+
+/// A monetary price. Backed by `rust_decimal::Decimal`, a fixed-precision decimal with up to 28
+/// significant digits, so exchange tick sizes survive
+/// round-tripping through JSON instead of being rounded away by `f64`.
+pub type Price = Decimal;
+
+/// A trade quantity, with the same precision guarantees as `Price`.
+pub type Volume = Decimal;
+
+/// The four order shapes `ExchangeApi::add_order` accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderType {
+    BuyLimit,
+    BuyMarket,
+    SellLimit,
+    SellMarket,
+}
+
+/// A snapshot of an exchange's best bid/ask and last trade price for a Pair.
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    pub timestamp: i64,
+    pub pair: Pair,
+    pub last_trade_price: Price,
+    pub lowest_ask: Price,
+    pub highest_bid: Price,
+    pub volume: Option<Volume>,
+}
+
+/// A snapshot of an exchange's order book for a Pair.
+#[derive(Debug, Clone)]
+pub struct Orderbook {
+    pub timestamp: i64,
+    pub pair: Pair,
+    pub asks: Vec<(Price, Volume)>,
+    pub bids: Vec<(Price, Volume)>,
+}
+
+/// The result of placing (or dry-running) an order.
+#[derive(Debug, Clone)]
+pub struct OrderInfo {
+    pub timestamp: i64,
+    pub identifier: Vec<String>,
+}